@@ -0,0 +1,151 @@
+//! Binary-Coded Decimal (BCD) data field encoding and decoding
+
+use crate::{BcdSsm, DATA_FIELD_END, DATA_FIELD_START, Message};
+
+/// The sign of a BCD-encoded value, taken from the SSM field
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Sign {
+    /// Positive, or the SSM does not indicate a sign
+    Positive,
+    /// Negative
+    Negative,
+}
+
+impl Message {
+    /// Returns the sign of a BCD-encoded value in this message, based on the SSM field
+    pub fn sign(&self) -> Sign {
+        match self.ssm_as_bcd() {
+            BcdSsm::Minus => Sign::Negative,
+            _ => Sign::Positive,
+        }
+    }
+
+    /// Decodes a BCD (binary-coded decimal) data value from this message
+    ///
+    /// `digit_widths` gives the width in bits of each decimal digit, starting with the least
+    /// significant digit. The digits are read from the data field (bits 11-29) starting at bit
+    /// 11. If any digit group has a value greater than 9, this function returns `None`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the total width of `digit_widths` is greater than 19 (the width
+    /// of the data field).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use arinc_429::Message;
+    /// let message = Message::from(0).with_field(11, 4, 5).with_field(15, 4, 2);
+    /// assert_eq!(message.bcd_value(&[4, 4]), Some(25));
+    /// ```
+    ///
+    pub fn bcd_value(&self, digit_widths: &[u8]) -> Option<u32> {
+        let mut bit = DATA_FIELD_START;
+        let mut value: u32 = 0;
+        let mut place: u32 = 1;
+        for &width in digit_widths {
+            assert!(
+                bit + width <= DATA_FIELD_END + 1,
+                "digit_widths exceed the BCD data field"
+            );
+            let digit = self.field(bit, width);
+            if digit > 9 {
+                return None;
+            }
+            value += digit * place;
+            place *= 10;
+            bit += width;
+        }
+        Some(value)
+    }
+
+    /// Returns a new message with a BCD (binary-coded decimal) data value encoded into it
+    ///
+    /// `digit_widths` has the same meaning as in [`bcd_value`](#method.bcd_value). `value` is
+    /// split into decimal digits, least significant first, and each digit is written into the
+    /// corresponding group of bits. All other bits of this message, including SDI, SSM, label and
+    /// parity, are left unchanged.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the total width of `digit_widths` is greater than 19, or if
+    /// `value` does not fit in the digits described by `digit_widths` (either because a digit of
+    /// `value` is larger than its digit group can hold, or because `value` has more digits than
+    /// `digit_widths` provides).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use arinc_429::Message;
+    /// let message = Message::from(0).set_bcd_value(&[4, 4], 25);
+    /// assert_eq!(message.bcd_value(&[4, 4]), Some(25));
+    /// ```
+    ///
+    pub fn set_bcd_value(self, digit_widths: &[u8], mut value: u32) -> Message {
+        let mut message = self;
+        let mut bit = DATA_FIELD_START;
+        for &width in digit_widths {
+            assert!(
+                bit + width <= DATA_FIELD_END + 1,
+                "digit_widths exceed the BCD data field"
+            );
+            let digit = value % 10;
+            let max_digit = if width >= 4 { 9 } else { (1u32 << width) - 1 };
+            assert!(
+                digit <= max_digit,
+                "value does not fit in the given digit_widths"
+            );
+            message = message.with_field(bit, width, digit);
+            value /= 10;
+            bit += width;
+        }
+        assert!(value == 0, "value does not fit in the given digit_widths");
+        message
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bcd_value_none_when_digit_exceeds_nine() {
+        // A 4-bit digit group can hold 0xa, which is not a valid BCD digit
+        let message = Message::from(0).with_field(11, 4, 0xa);
+        assert_eq!(message.bcd_value(&[4]), None);
+    }
+
+    #[test]
+    fn bcd_value_some_when_all_digits_valid() {
+        let message = Message::from(0).with_field(11, 4, 9).with_field(15, 4, 9);
+        assert_eq!(message.bcd_value(&[4, 4]), Some(99));
+    }
+
+    #[test]
+    fn set_bcd_value_round_trips() {
+        let message = Message::from(0).set_bcd_value(&[4, 4, 4], 123);
+        assert_eq!(message.bcd_value(&[4, 4, 4]), Some(123));
+    }
+
+    #[test]
+    #[should_panic]
+    fn set_bcd_value_panics_when_narrow_digit_overflows() {
+        // A 1-bit digit group can hold at most 1, so a digit of 9 does not fit
+        Message::from(0).set_bcd_value(&[1], 9);
+    }
+
+    #[test]
+    #[should_panic]
+    fn set_bcd_value_panics_when_value_has_extra_digits() {
+        // Only one digit group is provided, so a two-digit value does not fit
+        Message::from(0).set_bcd_value(&[4], 25);
+    }
+
+    #[test]
+    fn sign_is_positive_unless_ssm_is_minus() {
+        let message = Message::from(0).set_ssm_as_bcd(BcdSsm::Plus);
+        assert_eq!(message.sign(), Sign::Positive);
+        let message = Message::from(0).set_ssm_as_bcd(BcdSsm::Minus);
+        assert_eq!(message.sign(), Sign::Negative);
+    }
+}
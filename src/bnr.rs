@@ -0,0 +1,163 @@
+//! Binary (BNR) data field encoding and decoding
+//!
+//! This module encodes and decodes the magnitude as a sign bit plus an unsigned magnitude, not
+//! as two's-complement: a dedicated sign bit lies directly above the magnitude field. Note that this differs from some real-world ARINC 429
+//! equipment, which encodes BNR data fields in two's-complement; decoding a two's-complement word
+//! with [`bnr_value`](struct.Message.html#method.bnr_value) will give the wrong magnitude for
+//! negative values produced by such equipment.
+
+use crate::{DATA_FIELD_END, DATA_FIELD_START, Message};
+
+/// A decoded BNR (binary) data value
+///
+/// This is the raw signed integer encoded in the magnitude and sign bits of a BNR data field,
+/// before any engineering-unit scale factor is applied.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct BnrValue(i32);
+
+impl BnrValue {
+    /// Returns the raw signed integer value
+    pub fn raw(&self) -> i32 {
+        self.0
+    }
+
+    /// Multiplies this value by a scale factor (the engineering-unit value of one least
+    /// significant magnitude bit) and returns the result
+    pub fn scaled(&self, resolution: f64) -> f64 {
+        f64::from(self.0) * resolution
+    }
+
+    /// Multiplies this value by a scale factor and returns the result as an `f32`
+    pub fn scaled_f32(&self, resolution: f32) -> f32 {
+        self.0 as f32 * resolution
+    }
+}
+
+impl Message {
+    /// Decodes a BNR (binary) data value from this message
+    ///
+    /// `msb_bit` and `lsb_bit` are the 1-based bit numbers of the most and least significant bits
+    /// of the magnitude field. The bit directly above `msb_bit` is the sign bit: when it is set,
+    /// the decoded value is negative.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `lsb_bit` is less than 11, if `msb_bit` is greater than 28, or if
+    /// `msb_bit` is less than `lsb_bit`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use arinc_429::Message;
+    /// let message = Message::from(0).with_field(11, 5, 0b01010);
+    /// assert_eq!(message.bnr_value(15, 11).raw(), 0b01010);
+    /// ```
+    ///
+    pub fn bnr_value(&self, msb_bit: u8, lsb_bit: u8) -> BnrValue {
+        let width = Self::check_bnr_bits(msb_bit, lsb_bit);
+        let magnitude = self.field(lsb_bit, width) as i32;
+        let negative = self.field(msb_bit + 1, 1) != 0;
+        BnrValue(if negative { -magnitude } else { magnitude })
+    }
+
+    /// Returns a new message with a BNR (binary) data value encoded into it
+    ///
+    /// `msb_bit` and `lsb_bit` have the same meaning as in
+    /// [`bnr_value`](#method.bnr_value). Because the magnitude is stored as a sign and an
+    /// unsigned magnitude rather than in two's-complement form, the largest magnitude that fits
+    /// in `n` bits is `2^n - 1`; `value` is clamped to `[-(2^n - 1), 2^n - 1]` before being
+    /// encoded. All other bits of this message, including SDI, SSM, label and parity, are left
+    /// unchanged.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `lsb_bit` is less than 11, if `msb_bit` is greater than 28, or if
+    /// `msb_bit` is less than `lsb_bit`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use arinc_429::Message;
+    /// let message = Message::from(0).set_bnr_value(15, 11, -5);
+    /// assert_eq!(message.bnr_value(15, 11).raw(), -5);
+    /// ```
+    ///
+    pub fn set_bnr_value(self, msb_bit: u8, lsb_bit: u8, value: i32) -> Message {
+        let width = Self::check_bnr_bits(msb_bit, lsb_bit);
+        let max = (1i64 << width) - 1;
+        let min = -max;
+        let clamped = i64::from(value).clamp(min, max);
+        let negative = clamped < 0;
+        let magnitude = clamped.unsigned_abs() as u32;
+        self.with_field(lsb_bit, width, magnitude)
+            .with_field(msb_bit + 1, 1, negative as u32)
+    }
+
+    /// Checks that `msb_bit` and `lsb_bit` describe a valid BNR/BCD magnitude field and returns
+    /// its width
+    fn check_bnr_bits(msb_bit: u8, lsb_bit: u8) -> u8 {
+        assert!(
+            lsb_bit >= DATA_FIELD_START,
+            "lsb_bit must be at least {}",
+            DATA_FIELD_START
+        );
+        assert!(
+            msb_bit < DATA_FIELD_END,
+            "msb_bit must be less than {}",
+            DATA_FIELD_END
+        );
+        assert!(msb_bit >= lsb_bit, "msb_bit must not be less than lsb_bit");
+        msb_bit - lsb_bit + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_bnr_value_saturates_at_max() {
+        // A 5-bit magnitude field can hold at most 2^5 - 1 = 31
+        let message = Message::from(0).set_bnr_value(15, 11, 1000);
+        assert_eq!(message.bnr_value(15, 11).raw(), 31);
+    }
+
+    #[test]
+    fn set_bnr_value_saturates_at_min() {
+        // Sign-magnitude cannot represent -32, so the minimum is also -31
+        let message = Message::from(0).set_bnr_value(15, 11, -1000);
+        assert_eq!(message.bnr_value(15, 11).raw(), -31);
+    }
+
+    #[test]
+    fn set_bnr_value_round_trips_extremes() {
+        let max = Message::from(0).set_bnr_value(15, 11, 31);
+        assert_eq!(max.bnr_value(15, 11).raw(), 31);
+        let min = Message::from(0).set_bnr_value(15, 11, -31);
+        assert_eq!(min.bnr_value(15, 11).raw(), -31);
+    }
+
+    #[test]
+    fn bnr_value_zero_is_not_negative() {
+        let message = Message::from(0).set_bnr_value(15, 11, 0);
+        assert_eq!(message.bnr_value(15, 11).raw(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn check_bnr_bits_rejects_lsb_before_data_field() {
+        Message::from(0).set_bnr_value(15, DATA_FIELD_START - 1, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn check_bnr_bits_rejects_msb_at_data_field_end() {
+        Message::from(0).set_bnr_value(DATA_FIELD_END, 11, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn check_bnr_bits_rejects_msb_below_lsb() {
+        Message::from(0).set_bnr_value(11, 15, 0);
+    }
+}
@@ -0,0 +1,150 @@
+//! Reading and writing streams of messages to and from byte buffers
+//!
+//! This module is only available when the `std` feature is enabled.
+
+use std::io::{self, ErrorKind, Read, Write};
+
+use crate::Message;
+
+/// The byte order used to read or write the 32-bit words that make up a stream of messages
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Endian {
+    /// Most significant byte first
+    Big,
+    /// Least significant byte first
+    Little,
+}
+
+/// Types that can be read, one message at a time, from a byte stream
+pub trait Readable: Sized {
+    /// Reads one message from `reader`
+    ///
+    /// `endian` selects the byte order of the 4-byte word, and `label_swapped` selects whether
+    /// the word uses the on-wire label bit order (`false`) or has its label bits reversed
+    /// (`true`), as produced by some adapters.
+    fn read_from<R: Read>(reader: R, endian: Endian, label_swapped: bool) -> io::Result<Self>;
+}
+
+/// Types that can be written, one message at a time, to a byte stream
+pub trait Writeable {
+    /// Writes this message to `writer`
+    ///
+    /// `endian` and `label_swapped` have the same meaning as in
+    /// [`Readable::read_from`](trait.Readable.html#tymethod.read_from).
+    fn write_to<W: Write>(&self, writer: W, endian: Endian, label_swapped: bool) -> io::Result<()>;
+}
+
+impl Readable for Message {
+    fn read_from<R: Read>(mut reader: R, endian: Endian, label_swapped: bool) -> io::Result<Message> {
+        let mut buf = [0u8; 4];
+        reader.read_exact(&mut buf)?;
+        let bits = match endian {
+            Endian::Big => u32::from_be_bytes(buf),
+            Endian::Little => u32::from_le_bytes(buf),
+        };
+        Ok(if label_swapped {
+            Message::from_bits_label_swapped(bits)
+        } else {
+            Message::from(bits)
+        })
+    }
+}
+
+impl Writeable for Message {
+    fn write_to<W: Write>(&self, mut writer: W, endian: Endian, label_swapped: bool) -> io::Result<()> {
+        let bits = if label_swapped {
+            self.bits_label_swapped()
+        } else {
+            self.bits()
+        };
+        let buf = match endian {
+            Endian::Big => bits.to_be_bytes(),
+            Endian::Little => bits.to_le_bytes(),
+        };
+        writer.write_all(&buf)
+    }
+}
+
+impl Message {
+    /// Reads messages from `reader` until end-of-file and returns them in a `Vec`
+    ///
+    /// `endian` and `label_swapped` have the same meaning as in
+    /// [`Readable::read_from`](trait.Readable.html#tymethod.read_from). An end-of-file
+    /// encountered before any bytes of a word are read ends the stream normally; an end-of-file
+    /// in the middle of a word is an error.
+    pub fn read_all<R: Read>(
+        mut reader: R,
+        endian: Endian,
+        label_swapped: bool,
+    ) -> io::Result<Vec<Message>> {
+        let mut messages = Vec::new();
+        while let Some(first_byte) = read_first_byte_or_eof(&mut reader)? {
+            let chained = io::Cursor::new([first_byte]).chain(&mut reader);
+            messages.push(Message::read_from(chained, endian, label_swapped)?);
+        }
+        Ok(messages)
+    }
+
+    /// Writes `messages` to `writer`
+    ///
+    /// `endian` and `label_swapped` have the same meaning as in
+    /// [`Writeable::write_to`](trait.Writeable.html#tymethod.write_to).
+    pub fn write_all<W: Write>(
+        messages: &[Message],
+        mut writer: W,
+        endian: Endian,
+        label_swapped: bool,
+    ) -> io::Result<()> {
+        for message in messages {
+            message.write_to(&mut writer, endian, label_swapped)?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads one byte from `reader`, returning `None` if end-of-file is reached before any byte is
+/// read
+fn read_first_byte_or_eof<R: Read>(reader: &mut R) -> io::Result<Option<u8>> {
+    let mut byte = [0u8; 1];
+    match reader.read_exact(&mut byte) {
+        Ok(()) => Ok(Some(byte[0])),
+        Err(e) if e.kind() == ErrorKind::UnexpectedEof => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn read_all_on_empty_stream_returns_no_messages() {
+        let messages = Message::read_all(Cursor::new([]), Endian::Big, false).unwrap();
+        assert!(messages.is_empty());
+    }
+
+    #[test]
+    fn read_all_reads_every_complete_word() {
+        let bytes = [0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02];
+        let messages = Message::read_all(Cursor::new(bytes), Endian::Big, false).unwrap();
+        assert_eq!(messages, vec![Message::from(1), Message::from(2)]);
+    }
+
+    #[test]
+    fn read_all_errors_on_eof_in_the_middle_of_a_word() {
+        let bytes = [0x00, 0x00, 0x00];
+        let result = Message::read_all(Cursor::new(bytes), Endian::Big, false);
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn write_all_then_read_all_round_trips() {
+        let messages = vec![Message::from(0x12345678), Message::from(0x0)];
+        let mut buf = Vec::new();
+        Message::write_all(&messages, &mut buf, Endian::Little, true).unwrap();
+        let read_back = Message::read_all(Cursor::new(buf), Endian::Little, true).unwrap();
+        assert_eq!(read_back, messages);
+    }
+}
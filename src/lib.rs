@@ -4,6 +4,9 @@
 //! # Serialization/Deserialization
 //!
 //! When compiled with the `serde` feature, all types support serialization and deserialization.
+//! Human-readable formats (such as JSON or YAML) use a structured, self-describing
+//! representation, while compact formats (such as bincode) use the smallest possible binary
+//! representation.
 //!
 
 #![doc(html_root_url = "https://docs.rs/arinc_429/0.1.5")]
@@ -15,6 +18,9 @@ pub mod constants;
 #[macro_use]
 extern crate serde;
 
+#[cfg(all(feature = "serde", not(feature = "std")))]
+extern crate alloc;
+
 #[cfg(feature = "std")]
 use std as base;
 #[cfg(not(feature = "std"))]
@@ -23,6 +29,31 @@ use core as base;
 mod parity_error;
 pub use self::parity_error::ParityError;
 
+/// The first bit (1-based) of the BNR/BCD data field (bits 11-29)
+const DATA_FIELD_START: u8 = 11;
+/// The last bit (1-based) of the BNR/BCD data field (bits 11-29)
+const DATA_FIELD_END: u8 = 29;
+
+mod bnr;
+pub use self::bnr::BnrValue;
+
+mod bcd;
+pub use self::bcd::Sign;
+
+mod sdi;
+pub use self::sdi::Sdi;
+
+mod ssm;
+pub use self::ssm::{BcdSsm, BnrSsm};
+
+#[cfg(feature = "std")]
+mod io;
+#[cfg(feature = "std")]
+pub use self::io::{Endian, Readable, Writeable};
+
+#[cfg(feature = "serde")]
+mod serde_impl;
+
 /// An ARINC 429 message
 ///
 /// The bits of a message are represented exactly as transmitted on the wires, with the least
@@ -63,7 +94,6 @@ pub use self::parity_error::ParityError;
 /// ```
 ///
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Message(u32);
 
 impl Message {
@@ -72,6 +102,77 @@ impl Message {
         self.0
     }
 
+    /// Returns the value of a sub-field of this message
+    ///
+    /// `offset` is the 1-based bit number of the least significant bit of the field, using the
+    /// ARINC 429 bit numbering convention (bit 1 is the least significant bit of the word, and
+    /// bit 32 is the parity bit). `width` is the number of bits in the field. The returned value
+    /// is right-aligned, with the bit at `offset` in the least significant position.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `offset` is less than 1 or `offset + width` is greater than 33
+    /// (one past the parity bit).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use arinc_429::Message;
+    /// let message = Message::from(0b110);
+    /// assert_eq!(message.field(2, 2), 0b11);
+    /// ```
+    ///
+    pub fn field(&self, offset: u8, width: u8) -> u32 {
+        assert!(offset >= 1, "offset must be at least 1");
+        assert!(
+            u32::from(offset) + u32::from(width) <= 33,
+            "offset + width must not exceed 33"
+        );
+        let shift = offset - 1;
+        let mask = Self::field_mask(width);
+        (self.0 >> shift) & mask
+    }
+
+    /// Returns a new message with a sub-field replaced with a value
+    ///
+    /// `offset` and `width` have the same meaning as in [`field`](#method.field). `value` is
+    /// masked to `width` bits before being written; any bits of `value` beyond `width` are
+    /// discarded. All bits of this message outside the field are left unchanged.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `offset` is less than 1 or `offset + width` is greater than 33
+    /// (one past the parity bit).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use arinc_429::Message;
+    /// let message = Message::from(0).with_field(2, 2, 0b11);
+    /// assert_eq!(message.bits(), 0b110);
+    /// ```
+    ///
+    pub fn with_field(self, offset: u8, width: u8, value: u32) -> Message {
+        assert!(offset >= 1, "offset must be at least 1");
+        assert!(
+            u32::from(offset) + u32::from(width) <= 33,
+            "offset + width must not exceed 33"
+        );
+        let shift = offset - 1;
+        let mask = Self::field_mask(width);
+        let cleared = self.0 & !(mask << shift);
+        Message(cleared | ((value & mask) << shift))
+    }
+
+    /// Returns a mask with the least significant `width` bits set
+    fn field_mask(width: u8) -> u32 {
+        if width >= 32 {
+            u32::MAX
+        } else {
+            (1u32 << width) - 1
+        }
+    }
+
     /// Returns the bits of this message, but
     /// with the order of the 8 label bits reversed.
     pub fn bits_label_swapped(&self) -> u32 {
@@ -255,9 +356,6 @@ mod msg_fmt {
 
 /// ARINC 429 communication speeds
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename = "speed"))]
-#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum Speed {
     /// High speed, 100 kbps
     High,
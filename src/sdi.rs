@@ -0,0 +1,120 @@
+//! Source/Destination Identifier (SDI) field
+
+use crate::Message;
+
+/// The Source/Destination Identifier field of a message (bits 9-10)
+///
+/// In most messages, the SDI selects which system on a bus a word is addressed to (or was
+/// transmitted by). In some messages the SDI field is not used for addressing and instead carries
+/// two extra bits of data; use [`as_data`](#method.as_data) to read it that way.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Sdi {
+    /// SDI value 00
+    Zero,
+    /// SDI value 01
+    One,
+    /// SDI value 10
+    Two,
+    /// SDI value 11
+    Three,
+}
+
+impl Sdi {
+    /// Returns the 2-bit numeric value of this SDI
+    pub fn bits(&self) -> u8 {
+        match self {
+            Sdi::Zero => 0b00,
+            Sdi::One => 0b01,
+            Sdi::Two => 0b10,
+            Sdi::Three => 0b11,
+        }
+    }
+
+    /// Returns the numeric value of this SDI, for messages where the field is used as extra data
+    /// bits rather than for addressing
+    pub fn as_data(&self) -> u8 {
+        self.bits()
+    }
+}
+
+impl From<u8> for Sdi {
+    /// Creates an SDI from its 2-bit numeric value
+    ///
+    /// Only the least significant 2 bits of `bits` are used.
+    fn from(bits: u8) -> Self {
+        match bits & 0b11 {
+            0b00 => Sdi::Zero,
+            0b01 => Sdi::One,
+            0b10 => Sdi::Two,
+            _ => Sdi::Three,
+        }
+    }
+}
+
+impl From<Sdi> for u8 {
+    fn from(sdi: Sdi) -> u8 {
+        sdi.bits()
+    }
+}
+
+impl Message {
+    /// Returns the SDI (Source/Destination Identifier) field of this message (bits 9-10)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use arinc_429::{Message, Sdi};
+    /// let message = Message::from(0).with_field(9, 2, 0b10);
+    /// assert_eq!(message.sdi(), Sdi::Two);
+    /// ```
+    ///
+    pub fn sdi(&self) -> Sdi {
+        Sdi::from(self.field(9, 2) as u8)
+    }
+
+    /// Returns a new message with the SDI field set to `sdi`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use arinc_429::{Message, Sdi};
+    /// let message = Message::from(0).set_sdi(Sdi::Two);
+    /// assert_eq!(message.sdi(), Sdi::Two);
+    /// ```
+    ///
+    pub fn set_sdi(self, sdi: Sdi) -> Message {
+        self.with_field(9, 2, u32::from(u8::from(sdi)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bits_round_trip_through_from_u8() {
+        for (sdi, bits) in [
+            (Sdi::Zero, 0b00),
+            (Sdi::One, 0b01),
+            (Sdi::Two, 0b10),
+            (Sdi::Three, 0b11),
+        ] {
+            assert_eq!(sdi.bits(), bits);
+            assert_eq!(Sdi::from(bits), sdi);
+            assert_eq!(u8::from(sdi), bits);
+        }
+    }
+
+    #[test]
+    fn from_u8_ignores_extra_bits() {
+        assert_eq!(Sdi::from(0b1110), Sdi::Two);
+    }
+
+    #[test]
+    fn set_sdi_round_trips() {
+        for sdi in [Sdi::Zero, Sdi::One, Sdi::Two, Sdi::Three] {
+            let message = Message::from(0).set_sdi(sdi);
+            assert_eq!(message.sdi(), sdi);
+        }
+    }
+}
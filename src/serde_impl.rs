@@ -0,0 +1,166 @@
+//! Custom `Serialize`/`Deserialize` implementations
+//!
+//! Human-readable formats use a structured representation that is easy for a person to read;
+//! compact formats use the smallest binary representation, for interoperability with the values
+//! produced by earlier versions of this crate.
+
+use base::fmt;
+
+#[cfg(feature = "std")]
+use std::{format, string::String};
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String};
+
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::{Message, Speed};
+
+/// The human-readable representation of a [`Message`](struct.Message.html)
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Message")]
+struct HumanReadableMessage {
+    /// The label, as an octal string
+    label: String,
+    /// The SDI field (bits 9-10)
+    sdi: u32,
+    /// The SSM field (bits 30-31)
+    ssm: u32,
+    /// The data field (bits 11-29)
+    data: u32,
+    /// The parity bit (bit 32)
+    parity: u32,
+}
+
+impl From<&Message> for HumanReadableMessage {
+    fn from(message: &Message) -> Self {
+        HumanReadableMessage {
+            label: format!("{:03o}", message.label().0),
+            sdi: message.field(9, 2),
+            ssm: message.field(30, 2),
+            data: message.field(11, 19),
+            parity: message.field(32, 1),
+        }
+    }
+}
+
+fn message_from_repr<E: de::Error>(repr: HumanReadableMessage) -> Result<Message, E> {
+    let label = u8::from_str_radix(&repr.label, 8)
+        .map_err(|e| E::custom(format_args!("invalid octal label {:?}: {}", repr.label, e)))?;
+    let bits = Message::from(0)
+        .with_field(9, 2, repr.sdi)
+        .with_field(11, 19, repr.data)
+        .with_field(30, 2, repr.ssm)
+        .with_field(32, 1, repr.parity)
+        .bits();
+    let bits_with_label = Message::swap_label_bits((bits & 0xffffff00) | u32::from(label));
+    Ok(Message::from(bits_with_label))
+}
+
+impl Serialize for Message {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            HumanReadableMessage::from(self).serialize(serializer)
+        } else {
+            serializer.serialize_newtype_struct("Message", &self.bits())
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Message {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            HumanReadableMessage::deserialize(deserializer).and_then(message_from_repr)
+        } else {
+            struct CompactVisitor;
+
+            impl<'de> Visitor<'de> for CompactVisitor {
+                type Value = Message;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    formatter.write_str("a 32-bit message value")
+                }
+
+                fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Message, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    u32::deserialize(deserializer).map(Message::from)
+                }
+            }
+
+            deserializer.deserialize_newtype_struct("Message", CompactVisitor)
+        }
+    }
+}
+
+/// The name of a [`Speed`](enum.Speed.html) variant, used only for human-readable
+/// serialization/deserialization
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "speed")]
+#[serde(rename_all = "lowercase")]
+enum SpeedName {
+    High,
+    Low,
+}
+
+impl From<Speed> for SpeedName {
+    fn from(speed: Speed) -> Self {
+        match speed {
+            Speed::High => SpeedName::High,
+            Speed::Low => SpeedName::Low,
+        }
+    }
+}
+
+impl From<SpeedName> for Speed {
+    fn from(name: SpeedName) -> Self {
+        match name {
+            SpeedName::High => Speed::High,
+            SpeedName::Low => Speed::Low,
+        }
+    }
+}
+
+impl Serialize for Speed {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            SpeedName::from(*self).serialize(serializer)
+        } else {
+            let byte: u8 = match self {
+                Speed::Low => 0,
+                Speed::High => 1,
+            };
+            serializer.serialize_u8(byte)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Speed {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            SpeedName::deserialize(deserializer).map(Speed::from)
+        } else {
+            match u8::deserialize(deserializer)? {
+                0 => Ok(Speed::Low),
+                1 => Ok(Speed::High),
+                other => Err(de::Error::custom(format_args!(
+                    "invalid speed value {}, expected 0 or 1",
+                    other
+                ))),
+            }
+        }
+    }
+}
@@ -0,0 +1,185 @@
+//! Sign/Status Matrix (SSM) field
+
+use crate::Message;
+
+/// The interpretation of the SSM field (bits 30-31) used in BNR (binary) words
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum BnrSsm {
+    /// The transmitting equipment has failed
+    FailureWarning,
+    /// The data is not available
+    NoComputedData,
+    /// The equipment is in a functional test mode
+    FunctionalTest,
+    /// The data is valid
+    NormalOperation,
+}
+
+impl BnrSsm {
+    fn bits(&self) -> u8 {
+        match self {
+            BnrSsm::FailureWarning => 0b00,
+            BnrSsm::NoComputedData => 0b01,
+            BnrSsm::FunctionalTest => 0b10,
+            BnrSsm::NormalOperation => 0b11,
+        }
+    }
+
+    fn from_bits(bits: u8) -> Self {
+        match bits & 0b11 {
+            0b00 => BnrSsm::FailureWarning,
+            0b01 => BnrSsm::NoComputedData,
+            0b10 => BnrSsm::FunctionalTest,
+            _ => BnrSsm::NormalOperation,
+        }
+    }
+}
+
+/// The interpretation of the SSM field (bits 30-31) used in BCD words
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum BcdSsm {
+    /// Plus, North, East, Right, To, or Above
+    Plus,
+    /// The data is not available
+    NoComputedData,
+    /// The equipment is in a functional test mode
+    FunctionalTest,
+    /// Minus, South, West, Left, From, or Below
+    Minus,
+}
+
+impl BcdSsm {
+    fn bits(&self) -> u8 {
+        match self {
+            BcdSsm::Plus => 0b00,
+            BcdSsm::NoComputedData => 0b01,
+            BcdSsm::FunctionalTest => 0b10,
+            BcdSsm::Minus => 0b11,
+        }
+    }
+
+    fn from_bits(bits: u8) -> Self {
+        match bits & 0b11 {
+            0b00 => BcdSsm::Plus,
+            0b01 => BcdSsm::NoComputedData,
+            0b10 => BcdSsm::FunctionalTest,
+            _ => BcdSsm::Minus,
+        }
+    }
+}
+
+impl Message {
+    /// Returns the raw 2-bit value of the SSM (Sign/Status Matrix) field of this message
+    /// (bits 30-31)
+    pub fn ssm_raw(&self) -> u8 {
+        self.field(30, 2) as u8
+    }
+
+    /// Returns a new message with the raw 2-bit SSM field set to `ssm`
+    ///
+    /// Only the least significant 2 bits of `ssm` are used.
+    pub fn set_ssm_raw(self, ssm: u8) -> Message {
+        self.with_field(30, 2, u32::from(ssm))
+    }
+
+    /// Interprets the SSM field of this message using the BNR (binary) convention
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use arinc_429::{Message, BnrSsm};
+    /// let message = Message::from(0).set_ssm_as_bnr(BnrSsm::NormalOperation);
+    /// assert_eq!(message.ssm_as_bnr(), BnrSsm::NormalOperation);
+    /// ```
+    ///
+    pub fn ssm_as_bnr(&self) -> BnrSsm {
+        BnrSsm::from_bits(self.ssm_raw())
+    }
+
+    /// Returns a new message with the SSM field set to `ssm`, using the BNR (binary) convention
+    pub fn set_ssm_as_bnr(self, ssm: BnrSsm) -> Message {
+        self.set_ssm_raw(ssm.bits())
+    }
+
+    /// Interprets the SSM field of this message using the BCD convention
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use arinc_429::{Message, BcdSsm};
+    /// let message = Message::from(0).set_ssm_as_bcd(BcdSsm::Minus);
+    /// assert_eq!(message.ssm_as_bcd(), BcdSsm::Minus);
+    /// ```
+    ///
+    pub fn ssm_as_bcd(&self) -> BcdSsm {
+        BcdSsm::from_bits(self.ssm_raw())
+    }
+
+    /// Returns a new message with the SSM field set to `ssm`, using the BCD convention
+    pub fn set_ssm_as_bcd(self, ssm: BcdSsm) -> Message {
+        self.set_ssm_raw(ssm.bits())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bnr_ssm_bits_round_trip() {
+        for (ssm, bits) in [
+            (BnrSsm::FailureWarning, 0b00),
+            (BnrSsm::NoComputedData, 0b01),
+            (BnrSsm::FunctionalTest, 0b10),
+            (BnrSsm::NormalOperation, 0b11),
+        ] {
+            assert_eq!(ssm.bits(), bits);
+            assert_eq!(BnrSsm::from_bits(bits), ssm);
+        }
+    }
+
+    #[test]
+    fn bcd_ssm_bits_round_trip() {
+        for (ssm, bits) in [
+            (BcdSsm::Plus, 0b00),
+            (BcdSsm::NoComputedData, 0b01),
+            (BcdSsm::FunctionalTest, 0b10),
+            (BcdSsm::Minus, 0b11),
+        ] {
+            assert_eq!(ssm.bits(), bits);
+            assert_eq!(BcdSsm::from_bits(bits), ssm);
+        }
+    }
+
+    #[test]
+    fn from_bits_ignores_extra_bits() {
+        assert_eq!(BnrSsm::from_bits(0b1101), BnrSsm::NoComputedData);
+        assert_eq!(BcdSsm::from_bits(0b1101), BcdSsm::NoComputedData);
+    }
+
+    #[test]
+    fn set_ssm_as_bnr_round_trips() {
+        for ssm in [
+            BnrSsm::FailureWarning,
+            BnrSsm::NoComputedData,
+            BnrSsm::FunctionalTest,
+            BnrSsm::NormalOperation,
+        ] {
+            let message = Message::from(0).set_ssm_as_bnr(ssm);
+            assert_eq!(message.ssm_as_bnr(), ssm);
+        }
+    }
+
+    #[test]
+    fn set_ssm_as_bcd_round_trips() {
+        for ssm in [
+            BcdSsm::Plus,
+            BcdSsm::NoComputedData,
+            BcdSsm::FunctionalTest,
+            BcdSsm::Minus,
+        ] {
+            let message = Message::from(0).set_ssm_as_bcd(ssm);
+            assert_eq!(message.ssm_as_bcd(), ssm);
+        }
+    }
+}
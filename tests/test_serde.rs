@@ -9,14 +9,14 @@ extern crate serde_test;
 
 #[cfg(feature = "serde")]
 mod with_serde {
-    use serde_test::{Token, assert_tokens};
+    use serde_test::{Configure, Token, assert_tokens};
     use arinc_429::Speed;
     use arinc_429::Message;
 
     #[test]
     fn test_low_speed() {
         let speed = Speed::Low;
-        assert_tokens(&speed, &[
+        assert_tokens(&speed.readable(), &[
             Token::UnitVariant {
                 name: "speed",
                 variant: "low",
@@ -27,7 +27,7 @@ mod with_serde {
     #[test]
     fn test_high_speed() {
         let speed = Speed::High;
-        assert_tokens(&speed, &[
+        assert_tokens(&speed.readable(), &[
             Token::UnitVariant {
                 name: "speed",
                 variant: "high",
@@ -36,20 +36,92 @@ mod with_serde {
     }
 
     #[test]
-    fn test_message_zero() {
+    fn test_message_zero_human_readable() {
         let message = Message::from(0x0);
-        assert_tokens(&message, &[
+        assert_tokens(&message.readable(), &[
+            Token::Struct { name: "Message", len: 5 },
+            Token::Str("label"),
+            Token::Str("000"),
+            Token::Str("sdi"),
+            Token::U32(0),
+            Token::Str("ssm"),
+            Token::U32(0),
+            Token::Str("data"),
+            Token::U32(0),
+            Token::Str("parity"),
+            Token::U32(0),
+            Token::StructEnd,
+        ]);
+    }
+
+    #[test]
+    fn test_message_nonzero_human_readable() {
+        let message = Message::from(0xface1234);
+        assert_tokens(&message.readable(), &[
+            Token::Struct { name: "Message", len: 5 },
+            Token::Str("label"),
+            Token::Str("054"),
+            Token::Str("sdi"),
+            Token::U32(2),
+            Token::Str("ssm"),
+            Token::U32(3),
+            Token::Str("data"),
+            Token::U32(0x6b384),
+            Token::Str("parity"),
+            Token::U32(1),
+            Token::StructEnd,
+        ]);
+    }
+
+    #[test]
+    fn test_message_odd_parity_human_readable_round_trips() {
+        // Regression test: the parity bit (bit 32) must be carried through the human-readable
+        // representation, not silently dropped.
+        let message = Message::from(0x80000000);
+        assert_ne!(message, Message::from(0x0));
+        assert_tokens(&message.readable(), &[
+            Token::Struct { name: "Message", len: 5 },
+            Token::Str("label"),
+            Token::Str("000"),
+            Token::Str("sdi"),
+            Token::U32(0),
+            Token::Str("ssm"),
+            Token::U32(0),
+            Token::Str("data"),
+            Token::U32(0),
+            Token::Str("parity"),
+            Token::U32(1),
+            Token::StructEnd,
+        ]);
+    }
+
+    #[test]
+    fn test_message_zero_compact() {
+        let message = Message::from(0x0);
+        assert_tokens(&message.compact(), &[
             Token::NewtypeStruct { name: "Message" },
             Token::U32(0x0),
         ]);
     }
 
     #[test]
-    fn test_message_nonzero() {
+    fn test_message_nonzero_compact() {
         let message = Message::from(0xface1234);
-        assert_tokens(&message, &[
+        assert_tokens(&message.compact(), &[
             Token::NewtypeStruct { name: "Message" },
             Token::U32(0xface1234),
         ]);
     }
+
+    #[test]
+    fn test_low_speed_compact() {
+        let speed = Speed::Low;
+        assert_tokens(&speed.compact(), &[Token::U8(0)]);
+    }
+
+    #[test]
+    fn test_high_speed_compact() {
+        let speed = Speed::High;
+        assert_tokens(&speed.compact(), &[Token::U8(1)]);
+    }
 }